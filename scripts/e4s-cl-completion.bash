@@ -19,7 +19,7 @@ complete_profile() {
         fi
 
         if [ "$subaction" = "delete" -o "${#COMP_WORDS[@]}" = "4" ]; then
-            COMPREPLY=($(compgen -W "$(e4s-cl profile list -s)" "${COMP_WORDS[-1]}"))
+            COMPREPLY=($(compgen -W "$(e4s-cl profile list -s)" "${COMP_WORDS[$COMP_CWORD]}"))
             return
         fi
     fi